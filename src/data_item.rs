@@ -1,19 +1,105 @@
 use crate::errors::*;
 use crate::traits::{Close, High, Low, Open, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::io::{BufRead, BufReader, Read};
+
+/// A bar's position in time, expressed as epoch milliseconds.
+pub trait Timestamp {
+    fn timestamp(&self) -> Option<i64>;
+}
+
+/// A close price adjusted for splits and dividends.
+pub trait AdjClose {
+    fn adj_close(&self) -> f64;
+}
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct DataItem {
     open: f64,
     high: f64,
     low: f64,
     close: f64,
     volume: f64,
+    timestamp: Option<i64>,
+    adj_close: f64,
+}
+
+/// Deserializing straight onto the struct would bypass every invariant
+/// `DataItemBuilder::build` enforces, so incoming data is parsed into this
+/// shadow record first and funneled through the builder. `adj_close` is
+/// optional here too, matching the builder's "defaults to close" contract.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct DataItemRecord {
+    open: f64,
+    high: f64,
+    low: f64,
+    close: f64,
+    volume: f64,
+    #[serde(default)]
+    timestamp: Option<i64>,
+    #[serde(default)]
+    adj_close: Option<f64>,
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for DataItem {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let record = DataItemRecord::deserialize(deserializer)?;
+
+        let mut builder = DataItem::builder()
+            .open(record.open)
+            .high(record.high)
+            .low(record.low)
+            .close(record.close)
+            .volume(record.volume);
+
+        if let Some(ts) = record.timestamp {
+            builder = builder.timestamp(ts);
+        }
+        if let Some(adj_close) = record.adj_close {
+            builder = builder.adj_close(adj_close);
+        }
+
+        builder
+            .build()
+            .map_err(|e| serde::de::Error::custom(format!("invalid data item: {:?}", e)))
+    }
 }
 
 impl DataItem {
     pub fn builder() -> DataItemBuilder {
         DataItemBuilder::new()
     }
+
+    /// Back-adjusts the bar for a split/dividend by `factor`: open, high,
+    /// low, close and adj_close are multiplied by it while volume is
+    /// divided, so traded value is preserved across the adjustment.
+    ///
+    /// `factor` must be strictly positive: zero would divide volume by
+    /// zero, and a negative factor would flip the sign of every price
+    /// without swapping high/low, producing a bar that violates the
+    /// crate's own OHLC invariants.
+    pub fn adjust(&self, factor: f64) -> Result<DataItem> {
+        if factor <= 0.0 {
+            return Err(Error::from_kind(ErrorKind::DataItemInvalid));
+        }
+
+        Ok(DataItem {
+            open: self.open * factor,
+            high: self.high * factor,
+            low: self.low * factor,
+            close: self.close * factor,
+            adj_close: self.adj_close * factor,
+            volume: self.volume / factor,
+            timestamp: self.timestamp,
+        })
+    }
 }
 
 impl Open for DataItem {
@@ -46,12 +132,26 @@ impl Volume for DataItem {
     }
 }
 
+impl Timestamp for DataItem {
+    fn timestamp(&self) -> Option<i64> {
+        self.timestamp
+    }
+}
+
+impl AdjClose for DataItem {
+    fn adj_close(&self) -> f64 {
+        self.adj_close
+    }
+}
+
 pub struct DataItemBuilder {
     open: Option<f64>,
     high: Option<f64>,
     low: Option<f64>,
     close: Option<f64>,
     volume: Option<f64>,
+    timestamp: Option<i64>,
+    adj_close: Option<f64>,
 }
 
 impl DataItemBuilder {
@@ -62,6 +162,8 @@ impl DataItemBuilder {
             low: None,
             close: None,
             volume: None,
+            timestamp: None,
+            adj_close: None,
         }
     }
 
@@ -90,6 +192,20 @@ impl DataItemBuilder {
         self
     }
 
+    /// Attaches a timestamp (epoch millis) to the bar. Optional: omitting it
+    /// keeps existing callers working unchanged.
+    pub fn timestamp(mut self, val: i64) -> Self {
+        self.timestamp = Some(val);
+        self
+    }
+
+    /// Sets the split/dividend-adjusted close. Optional: defaults to
+    /// `close` when unspecified.
+    pub fn adj_close(mut self, val: f64) -> Self {
+        self.adj_close = Some(val);
+        self
+    }
+
     pub fn build(self) -> Result<DataItem> {
         if let (Some(open), Some(high), Some(low), Some(close), Some(volume)) =
             (self.open, self.high, self.low, self.close, self.volume)
@@ -109,6 +225,8 @@ impl DataItemBuilder {
                     low,
                     close,
                     volume,
+                    timestamp: self.timestamp,
+                    adj_close: self.adj_close.unwrap_or(close),
                 };
                 Ok(item)
             } else {
@@ -118,6 +236,237 @@ impl DataItemBuilder {
             Err(Error::from_kind(ErrorKind::DataItemIncomplete))
         }
     }
+
+    /// Like [`build`](Self::build), but repairs an inconsistent bar instead
+    /// of rejecting it: `high` is clamped up to the max of all four prices,
+    /// `low` is clamped down to the min, negative prices are floored to
+    /// zero (same as the strict `low >= 0.0` invariant `build` enforces),
+    /// and negative volume is floored to zero. Returns the corrected bar
+    /// along with which fields were adjusted to get there.
+    pub fn build_repaired(self) -> Result<Repaired> {
+        if let (Some(open), Some(high), Some(low), Some(close), Some(volume)) =
+            (self.open, self.high, self.low, self.close, self.volume)
+        {
+            let mut fields = Vec::new();
+
+            let max_price = open.max(high).max(low).max(close);
+            let min_price = open.min(high).min(low).min(close);
+
+            let high = if high < max_price {
+                fields.push(RepairedField::High);
+                max_price
+            } else {
+                high
+            };
+
+            let low = if low > min_price {
+                fields.push(RepairedField::Low);
+                min_price
+            } else {
+                low
+            };
+
+            // `max(_, 0.0)` is non-decreasing, so flooring each price this
+            // way can't disturb the low <= open/close <= high ordering
+            // just established above.
+            let has_negative_price = open < 0.0 || high < 0.0 || low < 0.0 || close < 0.0;
+            let (open, high, low, close) = (open.max(0.0), high.max(0.0), low.max(0.0), close.max(0.0));
+            if has_negative_price {
+                fields.push(RepairedField::Price);
+            }
+
+            let volume = if volume < 0.0 {
+                fields.push(RepairedField::Volume);
+                0.0
+            } else {
+                volume
+            };
+
+            let item = DataItem {
+                open,
+                high,
+                low,
+                close,
+                volume,
+                timestamp: self.timestamp,
+                adj_close: self.adj_close.unwrap_or(close),
+            };
+            Ok(Repaired { item, fields })
+        } else {
+            Err(Error::from_kind(ErrorKind::DataItemIncomplete))
+        }
+    }
+}
+
+/// A single field that [`DataItemBuilder::build_repaired`] had to correct
+/// on an inconsistent bar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RepairedField {
+    High,
+    Low,
+    Price,
+    Volume,
+}
+
+/// The outcome of [`DataItemBuilder::build_repaired`]: the corrected bar
+/// plus the list of fields that had to be clamped to produce it. An empty
+/// `fields` means the bar was already consistent.
+#[derive(Debug, Clone)]
+pub struct Repaired {
+    pub item: DataItem,
+    pub fields: Vec<RepairedField>,
+}
+
+/// Declares which column index of a delimited record holds each OHLCV
+/// field, so rows from real-world CSVs with arbitrary column order can be
+/// parsed without hard-coding positions.
+#[derive(Debug, Clone)]
+pub struct ColumnMap {
+    pub open: usize,
+    pub high: usize,
+    pub low: usize,
+    pub close: usize,
+    pub volume: usize,
+    pub timestamp: Option<usize>,
+}
+
+impl DataItem {
+    /// Builds a `DataItem` from a split CSV record using `columns` to
+    /// locate each field. Goes through the same builder validation as
+    /// manual construction, so malformed OHLC invariants are still
+    /// rejected.
+    pub fn from_csv_record(record: &[&str], columns: &ColumnMap) -> Result<DataItem> {
+        fn field<'a>(record: &[&'a str], idx: usize) -> Result<&'a str> {
+            record
+                .get(idx)
+                .copied()
+                .ok_or_else(|| Error::from_kind(ErrorKind::DataItemIncomplete))
+        }
+
+        fn parse_f64(record: &[&str], idx: usize) -> Result<f64> {
+            field(record, idx)?
+                .trim()
+                .parse()
+                .map_err(|_| Error::from_kind(ErrorKind::DataItemInvalid))
+        }
+
+        let mut builder = DataItem::builder()
+            .open(parse_f64(record, columns.open)?)
+            .high(parse_f64(record, columns.high)?)
+            .low(parse_f64(record, columns.low)?)
+            .close(parse_f64(record, columns.close)?)
+            .volume(parse_f64(record, columns.volume)?);
+
+        if let Some(idx) = columns.timestamp {
+            let ts: i64 = field(record, idx)?
+                .trim()
+                .parse()
+                .map_err(|_| Error::from_kind(ErrorKind::DataItemInvalid))?;
+            builder = builder.timestamp(ts);
+        }
+
+        builder.build()
+    }
+}
+
+/// Reads comma-separated OHLCV rows from `reader`, skipping blank lines,
+/// and builds a `DataItem` for each one via [`DataItem::from_csv_record`].
+pub fn read_ohlcv<R: Read>(reader: R, columns: &ColumnMap) -> Result<Vec<DataItem>> {
+    let mut items = Vec::new();
+    for line in BufReader::new(reader).lines() {
+        let line = line.map_err(|_| Error::from_kind(ErrorKind::DataItemInvalid))?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let fields: Vec<&str> = line.split(',').collect();
+        items.push(DataItem::from_csv_record(&fields, columns)?);
+    }
+    Ok(items)
+}
+
+/// A time-ordered run of `DataItem` bars.
+///
+/// Bars are kept sorted by `timestamp` at all times; `push` rejects a bar
+/// whose timestamp does not strictly follow the last one already stored
+/// (or any bar lacking a timestamp once the series has started tracking
+/// time).
+#[derive(Debug, Clone, Default)]
+pub struct OHLCVSeries {
+    bars: Vec<DataItem>,
+}
+
+impl OHLCVSeries {
+    pub fn new() -> Self {
+        Self { bars: Vec::new() }
+    }
+
+    pub fn bars(&self) -> &[DataItem] {
+        &self.bars
+    }
+
+    /// Appends a bar, enforcing that timestamps are strictly increasing.
+    pub fn push(&mut self, item: DataItem) -> Result<()> {
+        if let Some(last) = self.bars.last() {
+            match (last.timestamp(), item.timestamp()) {
+                (Some(last_ts), Some(ts)) if ts > last_ts => {}
+                _ => return Err(Error::from_kind(ErrorKind::DataItemInvalid)),
+            }
+        }
+        self.bars.push(item);
+        Ok(())
+    }
+
+    /// Looks up the bar recorded at exactly the given timestamp.
+    pub fn get(&self, timestamp: i64) -> Option<&DataItem> {
+        self.bars
+            .binary_search_by_key(&timestamp, |bar| bar.timestamp().unwrap_or(i64::MIN))
+            .ok()
+            .map(|idx| &self.bars[idx])
+    }
+
+    /// Returns the bars whose timestamp falls within `start..=end`. An
+    /// inverted range (`start > end`) yields an empty slice rather than
+    /// panicking.
+    pub fn slice(&self, start: i64, end: i64) -> &[DataItem] {
+        if start > end {
+            return &[];
+        }
+
+        let from = self
+            .bars
+            .partition_point(|bar| bar.timestamp().is_none_or(|ts| ts < start));
+        let to = self
+            .bars
+            .partition_point(|bar| bar.timestamp().is_none_or(|ts| ts <= end));
+        &self.bars[from..to]
+    }
+}
+
+/// Back-adjusts `bars` in place for a sequence of split/dividend events.
+///
+/// Each event is a `(timestamp, factor)` pair. Walking the events from the
+/// most recent to the oldest, every bar dated before an event is adjusted
+/// by that event's factor, so a bar preceding several events accumulates
+/// the combined factor of all of them. All-or-nothing: every factor is
+/// validated before any bar is touched, so a bad event leaves `bars`
+/// completely untouched rather than partially adjusted; see
+/// [`DataItem::adjust`].
+pub fn adjust_for_events(bars: &mut [DataItem], events: &[(i64, f64)]) -> Result<()> {
+    if events.iter().any(|(_, factor)| *factor <= 0.0) {
+        return Err(Error::from_kind(ErrorKind::DataItemInvalid));
+    }
+
+    let mut events = events.to_vec();
+    events.sort_by_key(|(ts, _)| *ts);
+
+    for (event_ts, factor) in events.into_iter().rev() {
+        for bar in bars.iter_mut() {
+            if bar.timestamp().is_some_and(|ts| ts < event_ts) {
+                *bar = bar.adjust(factor)?;
+            }
+        }
+    }
+    Ok(())
 }
 
 #[cfg(test)]
@@ -177,4 +526,257 @@ mod tests {
             assert_invalid(record)
         }
     }
+
+    fn bar(ts: i64, close: f64) -> DataItem {
+        DataItem::builder()
+            .open(close)
+            .high(close)
+            .low(close)
+            .close(close)
+            .volume(1.0)
+            .timestamp(ts)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_series_push_enforces_monotonic_timestamps() {
+        let mut series = OHLCVSeries::new();
+        series.push(bar(100, 10.0)).unwrap();
+        series.push(bar(200, 11.0)).unwrap();
+        assert!(series.push(bar(200, 12.0)).is_err());
+        assert!(series.push(bar(150, 12.0)).is_err());
+        assert_eq!(series.bars().len(), 2);
+    }
+
+    #[test]
+    fn test_series_get_and_slice() {
+        let mut series = OHLCVSeries::new();
+        for (ts, close) in [(100, 10.0), (200, 11.0), (300, 12.0)] {
+            series.push(bar(ts, close)).unwrap();
+        }
+
+        assert_eq!(series.get(200).unwrap().close(), 11.0);
+        assert!(series.get(250).is_none());
+
+        let slice = series.slice(150, 300);
+        assert_eq!(slice.len(), 2);
+        assert_eq!(slice[0].close(), 11.0);
+        assert_eq!(slice[1].close(), 12.0);
+    }
+
+    #[test]
+    fn test_series_slice_with_inverted_range_is_empty() {
+        let mut series = OHLCVSeries::new();
+        for (ts, close) in [(100, 10.0), (200, 11.0), (300, 12.0)] {
+            series.push(bar(ts, close)).unwrap();
+        }
+
+        assert!(series.slice(300, 100).is_empty());
+    }
+
+    #[test]
+    fn test_from_csv_record_with_arbitrary_column_order() {
+        // vendor feed: timestamp, close, open, high, low, volume
+        let columns = ColumnMap {
+            timestamp: Some(0),
+            close: 1,
+            open: 2,
+            high: 3,
+            low: 4,
+            volume: 5,
+        };
+        let record = ["100", "21.0", "20.0", "25.0", "15.0", "7500.0"];
+
+        let item = DataItem::from_csv_record(&record, &columns).unwrap();
+        assert_eq!(item.open(), 20.0);
+        assert_eq!(item.high(), 25.0);
+        assert_eq!(item.low(), 15.0);
+        assert_eq!(item.close(), 21.0);
+        assert_eq!(item.volume(), 7500.0);
+        assert_eq!(item.timestamp(), Some(100));
+    }
+
+    #[test]
+    fn test_from_csv_record_rejects_invalid_ohlc() {
+        let columns = ColumnMap {
+            open: 0,
+            high: 1,
+            low: 2,
+            close: 3,
+            volume: 4,
+            timestamp: None,
+        };
+        let record = ["20.0", "15.0", "25.0", "21.0", "7500.0"];
+        assert!(DataItem::from_csv_record(&record, &columns).is_err());
+    }
+
+    #[test]
+    fn test_read_ohlcv_parses_each_row() {
+        let columns = ColumnMap {
+            open: 0,
+            high: 1,
+            low: 2,
+            close: 3,
+            volume: 4,
+            timestamp: None,
+        };
+        let csv = "20.0,25.0,15.0,21.0,7500.0\n\n10.0,10.0,10.0,10.0,10.0\n";
+
+        let items = read_ohlcv(csv.as_bytes(), &columns).unwrap();
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].close(), 21.0);
+        assert_eq!(items[1].close(), 10.0);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_deserialize_rejects_invalid_ohlc() {
+        let json = r#"{"open":20.0,"high":5.0,"low":30.0,"close":21.0,"volume":-100.0}"#;
+        let result: std::result::Result<DataItem, _> = serde_json::from_str(json);
+        assert!(result.is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_deserialize_defaults_adj_close_to_close() {
+        let json = r#"{"open":20.0,"high":25.0,"low":15.0,"close":21.0,"volume":7500.0}"#;
+        let item: DataItem = serde_json::from_str(json).unwrap();
+        assert_eq!(item.adj_close(), item.close());
+    }
+
+    #[test]
+    fn test_adj_close_defaults_to_close() {
+        let item = DataItem::builder()
+            .open(20.0)
+            .high(25.0)
+            .low(15.0)
+            .close(21.0)
+            .volume(7500.0)
+            .build()
+            .unwrap();
+
+        assert_eq!(item.adj_close(), item.close());
+    }
+
+    #[test]
+    fn test_adjust_scales_prices_and_volume() {
+        let item = DataItem::builder()
+            .open(20.0)
+            .high(25.0)
+            .low(15.0)
+            .close(21.0)
+            .adj_close(21.0)
+            .volume(7500.0)
+            .build()
+            .unwrap();
+
+        let adjusted = item.adjust(0.5).unwrap();
+        assert_eq!(adjusted.open(), 10.0);
+        assert_eq!(adjusted.high(), 12.5);
+        assert_eq!(adjusted.low(), 7.5);
+        assert_eq!(adjusted.close(), 10.5);
+        assert_eq!(adjusted.adj_close(), 10.5);
+        assert_eq!(adjusted.volume(), 15000.0);
+    }
+
+    #[test]
+    fn test_adjust_rejects_non_positive_factor() {
+        let item = bar(100, 20.0);
+        assert!(item.adjust(0.0).is_err());
+        assert!(item.adjust(-1.0).is_err());
+    }
+
+    #[test]
+    fn test_adjust_for_events_accumulates_across_multiple_events() {
+        let mut bars = vec![bar(100, 20.0), bar(200, 20.0), bar(300, 20.0)];
+        // A 2-for-1 split at t=150, then another 2-for-1 at t=250.
+        let events = [(150, 0.5), (250, 0.5)];
+
+        adjust_for_events(&mut bars, &events).unwrap();
+
+        // Before both events: combined factor 0.25.
+        assert_eq!(bars[0].close(), 5.0);
+        // Between the two events: only the second applies.
+        assert_eq!(bars[1].close(), 10.0);
+        // After both events: untouched.
+        assert_eq!(bars[2].close(), 20.0);
+    }
+
+    #[test]
+    fn test_adjust_for_events_rejects_non_positive_factor() {
+        let mut bars = vec![bar(100, 20.0), bar(200, 20.0)];
+        assert!(adjust_for_events(&mut bars, &[(150, 0.0)]).is_err());
+    }
+
+    #[test]
+    fn test_adjust_for_events_leaves_bars_untouched_when_any_factor_invalid() {
+        let mut bars = vec![bar(100, 20.0), bar(200, 20.0), bar(300, 20.0)];
+        // The event at t=250 is valid on its own, but the one at t=150 is
+        // not, so nothing should be applied.
+        let events = [(250, 0.5), (150, -1.0)];
+
+        assert!(adjust_for_events(&mut bars, &events).is_err());
+
+        assert_eq!(bars[0].close(), 20.0);
+        assert_eq!(bars[1].close(), 20.0);
+        assert_eq!(bars[2].close(), 20.0);
+    }
+
+    #[test]
+    fn test_build_repaired_clamps_inconsistent_high_low_and_volume() {
+        let repaired = DataItem::builder()
+            .open(20.0)
+            .high(15.0) // lower than open/close: must be clamped up
+            .low(25.0) // higher than open/close: must be clamped down
+            .close(21.0)
+            .volume(-7500.0)
+            .build_repaired()
+            .unwrap();
+
+        assert_eq!(repaired.item.high(), 25.0);
+        assert_eq!(repaired.item.low(), 15.0);
+        assert_eq!(repaired.item.volume(), 0.0);
+        assert_eq!(
+            repaired.fields,
+            vec![RepairedField::High, RepairedField::Low, RepairedField::Volume]
+        );
+    }
+
+    #[test]
+    fn test_build_repaired_floors_negative_prices_to_zero() {
+        let repaired = DataItem::builder()
+            .open(-5.0)
+            .high(-3.0)
+            .low(-10.0)
+            .close(-4.0)
+            .volume(100.0)
+            .build_repaired()
+            .unwrap();
+
+        assert_eq!(repaired.item.open(), 0.0);
+        assert_eq!(repaired.item.high(), 0.0);
+        assert_eq!(repaired.item.low(), 0.0);
+        assert_eq!(repaired.item.close(), 0.0);
+        assert!(repaired.item.low() <= repaired.item.open());
+        assert!(repaired.item.open() <= repaired.item.high());
+        assert_eq!(repaired.fields, vec![RepairedField::Price]);
+    }
+
+    #[test]
+    fn test_build_repaired_leaves_consistent_bar_untouched() {
+        let repaired = DataItem::builder()
+            .open(20.0)
+            .high(25.0)
+            .low(15.0)
+            .close(21.0)
+            .volume(7500.0)
+            .build_repaired()
+            .unwrap();
+
+        assert_eq!(repaired.item.high(), 25.0);
+        assert_eq!(repaired.item.low(), 15.0);
+        assert_eq!(repaired.item.volume(), 7500.0);
+        assert!(repaired.fields.is_empty());
+    }
 }